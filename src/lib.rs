@@ -1,6 +1,8 @@
 use std::fmt;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use rayon::prelude::*;
+
 // --- Part 1: The Genome ---
 
 /// Simple Linear Congruential Generator (LCG) for deterministic randomness.
@@ -88,16 +90,28 @@ impl Genome {
         Genome { bytes: child_bytes }
     }
 
-    pub fn mutate(&mut self, rng: &mut Rng) {
+    /// Flips each bit independently with probability `rate`. Callers
+    /// typically source `rate` from an [`AdaptiveMutation`] controller
+    /// rather than a fixed constant.
+    pub fn mutate(&mut self, rng: &mut Rng, rate: f32) {
         for byte in self.bytes.iter_mut() {
             for bit in 0..8 {
-                if rng.next_f32() < 0.0001 {
+                if rng.next_f32() < rate {
                     *byte ^= 1 << bit;
                 }
             }
         }
     }
 
+    /// Number of differing bits between two genomes (XOR popcount), used
+    /// as a genetic-diversity measure.
+    pub fn hamming_distance(&self, other: &Genome) -> u32 {
+        self.bytes.iter().zip(other.bytes.iter()).map(|(a, b)| (a ^ b).count_ones()).sum()
+    }
+
+    /// Decodes the expressed `Phenotype`, including `max_lifespan`. Because
+    /// longevity is read straight out of genome bytes, it evolves for free
+    /// through ordinary `crossover`/`mutate` like every other trait.
     pub fn decode(&self) -> Phenotype {
         let get_u32 = |start: usize| -> u32 {
             let slice: [u8; 4] = self.bytes[start..start + 4].try_into().expect("Slice error");
@@ -139,17 +153,32 @@ impl fmt::Display for Genome {
 
 #[derive(Debug)]
 pub enum Event {
-    Birth { parent_id: u64 },
+    /// `parent_b` is `None` for asexual (fallback) reproduction, so
+    /// lineage can still be reconstructed from the JSONL log either way.
+    Birth { parent_a: u64, parent_b: Option<u64> },
     Death { id: u64, reason: String },
     Move { id: u64, x: u16, y: u16 },
+    /// Emitted the tick a creature's telomeres first reach zero, so driver
+    /// loops can report entry into senescence from the event log.
+    Senescent { id: u64 },
+    /// A creature injected into the world by a scenario, with no parent in
+    /// the simulated lineage. Kept distinct from `Birth` so reconstructing
+    /// ancestry from the log never mistakes an injected creature for its
+    /// own parent.
+    Spawned { id: u64 },
 }
 
 impl Event {
     pub fn to_jsonl(&self) -> String {
         match self {
-            Event::Birth { parent_id } => format!(r#"{{"type":"Birth","parent_id":{}}}"#, parent_id),
+            Event::Birth { parent_a, parent_b } => match parent_b {
+                Some(b) => format!(r#"{{"type":"Birth","parent_a":{},"parent_b":{}}}"#, parent_a, b),
+                None => format!(r#"{{"type":"Birth","parent_a":{},"parent_b":null}}"#, parent_a),
+            },
             Event::Death { id, reason } => format!(r#"{{"type":"Death","id":{},"reason":"{}"}}"#, id, reason),
             Event::Move { id, x, y } => format!(r#"{{"type":"Move","id":{},"x":{},"y":{}}}"#, id, x, y),
+            Event::Senescent { id } => format!(r#"{{"type":"Senescent","id":{}}}"#, id),
+            Event::Spawned { id } => format!(r#"{{"type":"Spawned","id":{}}}"#, id),
         }
     }
 }
@@ -164,6 +193,18 @@ pub struct Simulacrum {
     pub pos: Position,
     pub energy: f32,
     pub alive: bool,
+    pub age: u64,
+    /// Remaining lifespan budget, seeded from `phenotype.max_lifespan` and
+    /// decremented each tick (clamped at `0.0`) so `vitals()` can report
+    /// progressive aging. Decay is deterministic (driven only by
+    /// `phenotype.bmr` and the world's `senescence_bmr_factor`), so `World`
+    /// additionally schedules the exact tick it will hit zero up front
+    /// (`schedule_senescence`) rather than polling this field to detect the
+    /// transition to `senescent`.
+    pub telomeres: f32,
+    /// Set once `telomeres` reaches zero. Senescent creatures can no
+    /// longer reproduce and pay increased per-tick energy upkeep.
+    pub senescent: bool,
 }
 
 impl Simulacrum {
@@ -176,9 +217,19 @@ impl Simulacrum {
             pos: start_pos,
             energy: 100.0, // Initial energy buffer
             alive: true,
+            age: 0,
+            telomeres: phenotype.max_lifespan,
+            senescent: false,
         }
     }
 
+    /// Snapshot of `alive` and `telomeres` for driver loops that want to
+    /// report a creature's vitals after a tick without reaching into its
+    /// fields directly.
+    pub fn vitals(&self) -> (bool, f32) {
+        (self.alive, self.telomeres)
+    }
+
     pub fn move_to(&mut self, target: Position, world_size: u16) -> Option<Event> {
         if target.x >= world_size || target.y >= world_size {
             return None;
@@ -195,12 +246,548 @@ impl Simulacrum {
     }
 }
 
+// --- Part 4: Selection & Survival Pressure ---
+
+/// Computes a scalar fitness score for a living creature. Used by
+/// [`SelectionStrategy`] to pick reproduction parents and by
+/// [`SurvivalPressure`] to decide who gets culled when the population
+/// exceeds its carrying capacity.
+pub trait Fitness {
+    fn fitness(&self) -> f32;
+}
+
+impl Fitness for Simulacrum {
+    /// Default fitness: accumulated energy weighted by how long the
+    /// creature has survived.
+    fn fitness(&self) -> f32 {
+        self.energy.max(0.0) * (self.age as f32 + 1.0)
+    }
+}
+
+/// Strategy used to pick reproduction parents from the living population.
+#[derive(Debug, Clone, Copy)]
+pub enum SelectionStrategy {
+    /// Draw `k` random creatures (with replacement; `k` is capped at the
+    /// pool size) and return the fittest.
+    Tournament { k: usize },
+    /// Normalize fitness into a cumulative distribution and sample a point
+    /// in `[0, sum)`.
+    RouletteWheel,
+    /// Like `RouletteWheel`, but weights by fitness rank rather than raw
+    /// fitness so a single outlier can't dominate selection.
+    RankBased,
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        SelectionStrategy::Tournament { k: 3 }
+    }
+}
+
+impl SelectionStrategy {
+    /// Picks one parent from `pool` using `rng`. Returns `None` if `pool`
+    /// is empty.
+    pub fn select<'a>(&self, pool: &[&'a Simulacrum], rng: &mut Rng) -> Option<&'a Simulacrum> {
+        if pool.is_empty() {
+            return None;
+        }
+
+        match *self {
+            SelectionStrategy::Tournament { k } => {
+                let k = k.max(1).min(pool.len());
+                let mut best: Option<&Simulacrum> = None;
+                for _ in 0..k {
+                    let idx = (rng.next_u64() as usize) % pool.len();
+                    let candidate = pool[idx];
+                    if best.is_none_or(|b| candidate.fitness() > b.fitness()) {
+                        best = Some(candidate);
+                    }
+                }
+                best
+            }
+            SelectionStrategy::RouletteWheel => {
+                let total: f32 = pool.iter().map(|c| c.fitness().max(0.0)).sum();
+                if total <= 0.0 {
+                    let idx = (rng.next_u64() as usize) % pool.len();
+                    return Some(pool[idx]);
+                }
+                let point = rng.next_f32() * total;
+                let mut cumulative = 0.0;
+                for creature in pool {
+                    cumulative += creature.fitness().max(0.0);
+                    if point < cumulative {
+                        return Some(creature);
+                    }
+                }
+                pool.last().copied()
+            }
+            SelectionStrategy::RankBased => {
+                let mut ranked: Vec<&Simulacrum> = pool.to_vec();
+                ranked.sort_by(|a, b| {
+                    a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal)
+                });
+                let total_weight: f32 = (1..=ranked.len()).map(|r| r as f32).sum();
+                let point = rng.next_f32() * total_weight;
+                let mut cumulative = 0.0;
+                for (i, creature) in ranked.iter().enumerate() {
+                    cumulative += (i + 1) as f32;
+                    if point < cumulative {
+                        return Some(creature);
+                    }
+                }
+                ranked.last().copied()
+            }
+        }
+    }
+}
+
+/// Culls the population down to a carrying capacity once it's exceeded,
+/// removing the least-fit individuals first rather than waiting for them
+/// to starve.
+#[derive(Debug, Clone, Copy)]
+pub struct SurvivalPressure {
+    pub carrying_capacity: usize,
+}
+
+impl SurvivalPressure {
+    pub fn new(carrying_capacity: usize) -> Self {
+        Self { carrying_capacity }
+    }
+
+    /// Removes the lowest-fitness creatures until `creatures.len()` is at
+    /// most `carrying_capacity`, returning a `Death` event per cull.
+    pub fn apply(&self, creatures: &mut Vec<Simulacrum>) -> Vec<Event> {
+        let mut events = Vec::new();
+        if creatures.len() <= self.carrying_capacity {
+            return events;
+        }
+
+        let mut ranked: Vec<usize> = (0..creatures.len()).collect();
+        ranked.sort_by(|&a, &b| {
+            creatures[a]
+                .fitness()
+                .partial_cmp(&creatures[b].fitness())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let excess = creatures.len() - self.carrying_capacity;
+        let mut to_remove: Vec<usize> = ranked.into_iter().take(excess).collect();
+        to_remove.sort_by(|a, b| b.cmp(a)); // Descending, so removal doesn't shift earlier indices.
+
+        for index in to_remove {
+            let id = creatures[index].id;
+            creatures.remove(index);
+            events.push(Event::Death { id, reason: "Survival Pressure".to_string() });
+        }
+
+        events
+    }
+}
+
+/// Controls the per-bit mutation rate passed into `Genome::mutate`,
+/// raising it when the population stagnates or loses genetic diversity
+/// and lowering it when diversity is healthy, instead of a fixed constant.
+#[derive(Debug, Clone)]
+pub struct AdaptiveMutation {
+    pub base: f32,
+    pub gain: f32,
+    pub stagnation_gain: f32,
+    pub stagnation_window: usize,
+    pub sample_size: usize,
+    best_fitness_history: Vec<f32>,
+}
+
+impl AdaptiveMutation {
+    pub fn new(base: f32) -> Self {
+        AdaptiveMutation {
+            base,
+            gain: 2.0,
+            stagnation_gain: 1.0,
+            stagnation_window: 10,
+            sample_size: 20,
+            best_fitness_history: Vec::new(),
+        }
+    }
+
+    /// Mean pairwise genome Hamming distance over a random sample of
+    /// creatures, normalized to `[0, 1]` (512 bits is the max distance
+    /// between two 64-byte genomes).
+    fn diversity(&self, creatures: &[Simulacrum], rng: &mut Rng) -> f32 {
+        if creatures.len() < 2 {
+            return 1.0;
+        }
+
+        let mut total_distance = 0u32;
+        let mut pairs = 0u32;
+        for _ in 0..self.sample_size.min(creatures.len()) {
+            let i = (rng.next_u64() as usize) % creatures.len();
+            let j = (rng.next_u64() as usize) % creatures.len();
+            if i == j {
+                continue;
+            }
+            total_distance += creatures[i].genome.hamming_distance(&creatures[j].genome);
+            pairs += 1;
+        }
+
+        if pairs == 0 {
+            return 1.0;
+        }
+        (total_distance as f32 / pairs as f32) / 512.0
+    }
+
+    /// Number of trailing generations, out of the recorded history, that
+    /// failed to beat the best fitness seen so far.
+    fn stagnant_generations(&self) -> usize {
+        let mut running_best = f32::MIN;
+        let mut stagnant = 0;
+        for &fitness in &self.best_fitness_history {
+            if fitness > running_best {
+                running_best = fitness;
+                stagnant = 0;
+            } else {
+                stagnant += 1;
+            }
+        }
+        stagnant
+    }
+
+    /// Computes this generation's mutation rate from current population
+    /// diversity and stagnation, and records `best_fitness` for future
+    /// stagnation checks.
+    pub fn rate(&mut self, creatures: &[Simulacrum], best_fitness: f32, rng: &mut Rng) -> f32 {
+        let diversity = self.diversity(creatures, rng);
+
+        // Keep one entry beyond the window: the oldest serves as the
+        // reference the trailing `stagnation_window` generations must beat.
+        self.best_fitness_history.push(best_fitness);
+        if self.best_fitness_history.len() > self.stagnation_window + 1 {
+            self.best_fitness_history.remove(0);
+        }
+
+        let mut rate = self.base * (1.0 + self.gain * (1.0 - diversity));
+        if self.stagnant_generations() >= self.stagnation_window {
+            rate *= 1.0 + self.stagnation_gain;
+        }
+        rate.clamp(0.0, 1.0)
+    }
+}
+
+// --- Part 6: Scenarios & Scheduled Events ---
+
+/// The coefficients behind `World::calculate_energy`'s standing-wave
+/// energy field, broken out so a `Scenario` can tune them instead of
+/// recompiling.
+#[derive(Debug, Clone, Copy)]
+pub struct EnergyLandscape {
+    pub time_scale: f32,
+    pub space_scale: f32,
+    pub base: f32,
+    pub amplitude: f32,
+}
+
+impl Default for EnergyLandscape {
+    fn default() -> Self {
+        EnergyLandscape { time_scale: 0.01, space_scale: 0.1, base: 1.0, amplitude: 10.0 }
+    }
+}
+
+/// A `ResourcePulse` currently in effect, counting down to expiry.
+#[derive(Debug, Clone, Copy)]
+pub struct ActivePulse {
+    pub center: Position,
+    pub radius: f32,
+    pub magnitude: f32,
+    pub remaining_ticks: u32,
+}
+
+/// An environment event injected into the world at a specific tick.
+#[derive(Debug, Clone)]
+pub enum ScheduledEvent {
+    /// Spawns `count` fresh, randomly-genomed creatures seeded from `seed`.
+    InjectCreatures { count: usize, seed: u64 },
+    /// Temporarily boosts `energy_at` within `radius` of `center` by
+    /// `magnitude`, for `duration` ticks.
+    ResourcePulse { center: Position, radius: f32, magnitude: f32, duration: u32 },
+    /// Kills a random `fraction` of the living population outright.
+    Cataclysm { fraction: f32 },
+}
+
+/// A reproducible experiment config: world parameters plus a timeline of
+/// `ScheduledEvent`s, loadable from a config file instead of constants.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    pub world_size: u16,
+    pub seed: u64,
+    pub landscape: EnergyLandscape,
+    pub repro_threshold: f32,
+    pub repro_cost: f32,
+    pub schedule: Vec<(u64, ScheduledEvent)>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Scenario {
+            world_size: 200,
+            seed: 42,
+            landscape: EnergyLandscape::default(),
+            repro_threshold: 50.0,
+            repro_cost: 25.0,
+            schedule: Vec::new(),
+        }
+    }
+}
+
+impl Scenario {
+    /// Parses a scenario from a small line-oriented config format:
+    /// blank lines and `#` comments are ignored, `key=value` lines set
+    /// world parameters, and lines starting with a tick number schedule an
+    /// event, e.g. `250 ResourcePulse center_x=50 center_y=50 radius=20
+    /// magnitude=5 duration=100`.
+    pub fn parse(text: &str) -> Result<Scenario, String> {
+        let mut scenario = Scenario::default();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let starts_with_tick = line
+                .split_whitespace()
+                .next()
+                .is_some_and(|tok| tok.chars().all(|c| c.is_ascii_digit()));
+
+            if starts_with_tick {
+                scenario.schedule.push(Self::parse_scheduled_event(line)?);
+            } else {
+                Self::apply_param(&mut scenario, line)?;
+            }
+        }
+
+        scenario.schedule.sort_by_key(|(at, _)| *at);
+        Ok(scenario)
+    }
+
+    fn apply_param(scenario: &mut Scenario, line: &str) -> Result<(), String> {
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("malformed scenario parameter: {}", line))?;
+        let value = value.trim();
+        let parse_f32 = |v: &str| v.parse::<f32>().map_err(|e| e.to_string());
+
+        match key.trim() {
+            "world_size" => scenario.world_size = value.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+            "seed" => scenario.seed = value.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+            "time_scale" => scenario.landscape.time_scale = parse_f32(value)?,
+            "space_scale" => scenario.landscape.space_scale = parse_f32(value)?,
+            "base" => scenario.landscape.base = parse_f32(value)?,
+            "amplitude" => scenario.landscape.amplitude = parse_f32(value)?,
+            "repro_threshold" => scenario.repro_threshold = parse_f32(value)?,
+            "repro_cost" => scenario.repro_cost = parse_f32(value)?,
+            other => return Err(format!("unknown scenario parameter: {}", other)),
+        }
+        Ok(())
+    }
+
+    fn parse_scheduled_event(line: &str) -> Result<(u64, ScheduledEvent), String> {
+        let mut parts = line.split_whitespace();
+        let tick: u64 = parts
+            .next()
+            .unwrap()
+            .parse()
+            .map_err(|e: std::num::ParseIntError| e.to_string())?;
+        let kind = parts.next().ok_or_else(|| format!("missing event kind: {}", line))?;
+
+        let mut fields = std::collections::HashMap::new();
+        for part in parts {
+            let (k, v) = part
+                .split_once('=')
+                .ok_or_else(|| format!("malformed event field: {}", part))?;
+            fields.insert(k.to_string(), v.to_string());
+        }
+        let field = |key: &str| -> Result<f32, String> {
+            fields
+                .get(key)
+                .ok_or_else(|| format!("missing field `{}` for {} event", key, kind))?
+                .parse::<f32>()
+                .map_err(|e| e.to_string())
+        };
+        // Integer-typed fields (seed, count, duration, center_x/y) must not
+        // round-trip through f32: it only has 24 bits of exact integer
+        // precision, so a seed above ~16.7M would silently load as a
+        // different integer than what's written in the config.
+        let field_int = |key: &str| -> Result<&str, String> {
+            fields
+                .get(key)
+                .map(String::as_str)
+                .ok_or_else(|| format!("missing field `{}` for {} event", key, kind))
+        };
+
+        let event = match kind {
+            "InjectCreatures" => ScheduledEvent::InjectCreatures {
+                count: field_int("count")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+                seed: field_int("seed")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+            },
+            "ResourcePulse" => ScheduledEvent::ResourcePulse {
+                center: Position {
+                    x: field_int("center_x")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+                    y: field_int("center_y")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+                },
+                radius: field("radius")?,
+                magnitude: field("magnitude")?,
+                duration: field_int("duration")?.parse().map_err(|e: std::num::ParseIntError| e.to_string())?,
+            },
+            "Cataclysm" => ScheduledEvent::Cataclysm { fraction: field("fraction")? },
+            other => return Err(format!("unknown scheduled event kind: {}", other)),
+        };
+
+        Ok((tick, event))
+    }
+}
+
+// --- Part 7: Discrete-Event Scheduling ---
+//
+// STATUS: partial. The goal of this subsystem (per the request that added
+// it) is for large, sparsely active worlds to scale with the number of
+// pending actions rather than population * ticks. Only `SenescentDeath` is
+// actually scheduled and consumed off `EventQueue` today (see
+// `World::schedule_senescence` and the Pass 0.5 consumer in `World::tick`).
+// `ReproductionReady` and `MovementDecision` are defined but never
+// produced, and `World::tick`'s Pass 1 — by far the dominant per-tick cost,
+// since it evaluates gain/cost/movement for every living creature — still
+// scans the full population unconditionally every tick, exactly as before
+// this subsystem existed. Migrating Pass 1 onto the queue is a bigger
+// architectural change (reproduction eligibility and movement both depend
+// on signals that change unpredictably tick to tick, unlike the
+// deterministic telomere decay senescence rides on) and remains
+// unaddressed follow-up work, not something this subsystem delivers yet.
+
+/// A creature's next significant action, scheduled ahead of time instead
+/// of being discovered by polling every creature every tick.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum EntityAction {
+    ReproductionReady { id: u64 },
+    SenescentDeath { id: u64 },
+    MovementDecision { id: u64 },
+}
+
+impl EntityAction {
+    pub fn entity_id(&self) -> u64 {
+        match *self {
+            EntityAction::ReproductionReady { id }
+            | EntityAction::SenescentDeath { id }
+            | EntityAction::MovementDecision { id } => id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct QueueEntry {
+    at_tick: u64,
+    seq: u64,
+    generation: u64,
+    action: EntityAction,
+}
+
+impl Ord for QueueEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.at_tick, self.seq).cmp(&(other.at_tick, other.seq))
+    }
+}
+
+impl PartialOrd for QueueEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A binary-heap scheduler keyed by tick: creatures enqueue their next
+/// significant action instead of every creature being polled every tick.
+/// Entries are stamped with the entity's current "generation"; `cancel`
+/// bumps that generation so any already-queued entries for a dead entity
+/// are discarded the next time they'd fire, without having to scan the
+/// heap to remove them.
+#[derive(Debug, Default)]
+pub struct EventQueue {
+    heap: std::collections::BinaryHeap<std::cmp::Reverse<QueueEntry>>,
+    next_seq: u64,
+    generations: std::collections::HashMap<u64, u64>,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        EventQueue {
+            heap: std::collections::BinaryHeap::new(),
+            next_seq: 0,
+            generations: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Schedules `action` to fire at `at_tick`.
+    pub fn push(&mut self, at_tick: u64, action: EntityAction) {
+        let generation = *self.generations.get(&action.entity_id()).unwrap_or(&0);
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.heap.push(std::cmp::Reverse(QueueEntry { at_tick, seq, generation, action }));
+    }
+
+    /// Invalidates every pending entry for `id` (e.g. the entity died).
+    /// Stale entries are discarded lazily by `pop_ready` rather than
+    /// removed from the heap up front.
+    pub fn cancel(&mut self, id: u64) {
+        *self.generations.entry(id).or_insert(0) += 1;
+    }
+
+    /// Pops every still-valid action scheduled at or before `now`.
+    pub fn pop_ready(&mut self, now: u64) -> Vec<EntityAction> {
+        let mut ready = Vec::new();
+        while let Some(std::cmp::Reverse(entry)) = self.heap.peek() {
+            if entry.at_tick > now {
+                break;
+            }
+            let std::cmp::Reverse(entry) = self.heap.pop().unwrap();
+            let current_generation = *self.generations.get(&entry.action.entity_id()).unwrap_or(&0);
+            if entry.generation == current_generation {
+                ready.push(entry.action);
+            }
+            // Stale entry (entity cancelled since this was scheduled): drop it.
+        }
+        ready
+    }
+}
+
 pub struct World {
     pub size: u16,
     pub creatures: Vec<Simulacrum>,
     pub tick_count: u64,
     pub rng: Rng,
     pub next_id: u64,
+    pub repro_threshold: f32,
+    pub repro_cost: f32,
+    pub selection: SelectionStrategy,
+    pub survival: SurvivalPressure,
+    pub mutation: AdaptiveMutation,
+    /// The world's founding seed, kept around (distinct from `rng`'s
+    /// current state) so per-entity RNG substreams in `tick`'s parallel
+    /// pass stay reproducible across runs.
+    pub seed: u64,
+    /// Extra telomere decay per tick, scaled by `phenotype.bmr`, on top of
+    /// the base 1.0/tick decay. Higher metabolic load ages a creature faster.
+    pub senescence_bmr_factor: f32,
+    /// Multiplier applied to a senescent creature's per-tick energy upkeep.
+    pub senescent_upkeep_multiplier: f32,
+    pub landscape: EnergyLandscape,
+    /// Tick-ascending timeline of events still to be applied.
+    pub schedule: Vec<(u64, ScheduledEvent)>,
+    /// `ResourcePulse`s currently boosting `energy_at`.
+    pub active_pulses: Vec<ActivePulse>,
+    /// Discrete-event scheduler for per-creature actions. Partial adoption
+    /// only — see the "STATUS" note above `EntityAction`: `tick` consumes
+    /// due `SenescentDeath` actions from this queue each tick instead of
+    /// checking every creature's telomeres, but Pass 1's population-wide
+    /// scan (the dominant per-tick cost) is unchanged. Every death path
+    /// keeps this queue's cancellations in sync regardless.
+    pub action_queue: EventQueue,
 }
 
 impl World {
@@ -211,130 +798,1074 @@ impl World {
             tick_count: 0,
             rng: Rng::new(seed),
             next_id: 1,
+            repro_threshold: 50.0,
+            repro_cost: 25.0,
+            selection: SelectionStrategy::default(),
+            survival: SurvivalPressure::new(200),
+            mutation: AdaptiveMutation::new(0.0001),
+            seed,
+            senescence_bmr_factor: 1.0,
+            senescent_upkeep_multiplier: 1.5,
+            landscape: EnergyLandscape::default(),
+            schedule: Vec::new(),
+            active_pulses: Vec::new(),
+            action_queue: EventQueue::new(),
         }
     }
 
+    /// Builds a `World` from a `Scenario`, wiring in its energy landscape,
+    /// reproduction costs, and scheduled events so experiments are
+    /// reproducible from a config file rather than recompiled constants.
+    pub fn from_scenario(scenario: Scenario) -> Self {
+        let mut world = World::new(scenario.world_size, scenario.seed);
+        world.landscape = scenario.landscape;
+        world.repro_threshold = scenario.repro_threshold;
+        world.repro_cost = scenario.repro_cost;
+        world.schedule = scenario.schedule;
+        world
+    }
+
+    /// Splitmix64-style mixing of the world seed, tick count, and creature
+    /// id into a single well-distributed seed. Gives each creature an
+    /// independent, reproducible RNG substream for `tick`'s parallel pass.
+    fn splitmix64_seed(world_seed: u64, tick: u64, id: u64) -> u64 {
+        let mut z = world_seed
+            ^ tick.wrapping_mul(0x9E3779B97F4A7C15)
+            ^ id.wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
     /// Static calculation of energy to avoid borrow checker issues with `self`.
-    pub fn calculate_energy(tick: u64, pos: Position) -> f32 {
-        let t = tick as f32 * 0.01;
-        let x = pos.x as f32 * 0.1;
-        let y = pos.y as f32 * 0.1;
-        
+    pub fn calculate_energy(tick: u64, pos: Position, landscape: &EnergyLandscape) -> f32 {
+        let t = tick as f32 * landscape.time_scale;
+        let x = pos.x as f32 * landscape.space_scale;
+        let y = pos.y as f32 * landscape.space_scale;
+
         let pattern = (t + x).sin() * (t + y).cos();
         let norm = (pattern + 1.0) / 2.0;
-        
-        // Base 1.0 + Variable up to 10.0
-        1.0 + (10.0 * norm)
+
+        landscape.base + (landscape.amplitude * norm)
     }
 
     pub fn energy_at(&self, pos: Position) -> f32 {
-        Self::calculate_energy(self.tick_count, pos)
+        let mut energy = Self::calculate_energy(self.tick_count, pos, &self.landscape);
+        for pulse in &self.active_pulses {
+            if pos.dist(&pulse.center) <= pulse.radius {
+                energy += pulse.magnitude;
+            }
+        }
+        energy
+    }
+
+    /// Schedules `creature`'s eventual transition to senescence on the
+    /// action queue. Telomere decay is fixed per creature (driven only by
+    /// its `bmr` and the world's `senescence_bmr_factor`, never by
+    /// randomness), so the tick it will reach zero can be computed once up
+    /// front instead of polled for on every tick. Creatures added directly
+    /// to `self.creatures` by a driver, bypassing `World`'s own
+    /// reproduction/injection paths, won't be scheduled this way.
+    fn schedule_senescence(&mut self, creature: &Simulacrum) {
+        let decay = 1.0 + creature.phenotype.bmr * self.senescence_bmr_factor;
+        if decay <= 0.0 {
+            return;
+        }
+        let ticks_until = (creature.telomeres / decay).ceil().max(1.0) as u64;
+        self.action_queue.push(
+            self.tick_count + ticks_until,
+            EntityAction::SenescentDeath { id: creature.id },
+        );
+    }
+
+    /// Ages and expires active `ResourcePulse`s, then applies any
+    /// scheduled events due by `tick` (in schedule order), so a new pulse
+    /// is in effect for exactly `duration` ticks starting with this one.
+    fn apply_due_schedule(&mut self, tick: u64, events: &mut Vec<Event>) {
+        for pulse in &mut self.active_pulses {
+            pulse.remaining_ticks = pulse.remaining_ticks.saturating_sub(1);
+        }
+        self.active_pulses.retain(|p| p.remaining_ticks > 0);
+
+        while self.schedule.first().is_some_and(|(at, _)| *at <= tick) {
+            let (_, event) = self.schedule.remove(0);
+            match event {
+                ScheduledEvent::InjectCreatures { count, seed } => {
+                    let mut rng = Rng::new(seed);
+                    for _ in 0..count {
+                        let genome = Genome::from_seed(rng.next_u64());
+                        let pos = Position {
+                            x: (rng.next_u64() % self.size.max(1) as u64) as u16,
+                            y: (rng.next_u64() % self.size.max(1) as u64) as u16,
+                        };
+                        let id = self.next_id;
+                        self.next_id += 1;
+                        let creature = Simulacrum::new(id, genome, pos);
+                        self.schedule_senescence(&creature);
+                        self.creatures.push(creature);
+                        events.push(Event::Spawned { id });
+                    }
+                }
+                ScheduledEvent::ResourcePulse { center, radius, magnitude, duration } => {
+                    self.active_pulses.push(ActivePulse { center, radius, magnitude, remaining_ticks: duration });
+                }
+                ScheduledEvent::Cataclysm { fraction } => {
+                    let kill_count = ((self.creatures.len() as f32) * fraction.clamp(0.0, 1.0)).round() as usize;
+                    for _ in 0..kill_count {
+                        if self.creatures.is_empty() {
+                            break;
+                        }
+                        let idx = (self.rng.next_u64() as usize) % self.creatures.len();
+                        let id = self.creatures[idx].id;
+                        self.creatures.remove(idx);
+                        self.action_queue.cancel(id);
+                        events.push(Event::Death { id, reason: "Cataclysm".to_string() });
+                    }
+                }
+            }
+        }
     }
 
     pub fn tick(&mut self) -> Vec<Event> {
         self.tick_count += 1;
         let current_tick = self.tick_count; // Capture for use in closure/loop
         let mut events = Vec::new();
-        
+
+        // Pass 0: Apply any scenario-scheduled environment events due this
+        // tick before the main creature loop runs.
+        self.apply_due_schedule(current_tick, &mut events);
+
+        // Pass 0.5: Consume due actions from the scheduler. The tick a
+        // creature becomes senescent is scheduled at creation time (see
+        // `schedule_senescence`) rather than discovered by checking every
+        // creature's telomeres every tick, even though `telomeres` itself
+        // is still decremented every tick in Pass 1 below for reporting.
+        // `tick_count` still advances by one every call rather than
+        // jumping to the next due action: Pass 1 has to evaluate
+        // gain/cost/movement for every living creature each tick regardless
+        // (those depend on the continuously changing energy landscape, not
+        // on discrete scheduled actions), so there's no tick with nothing
+        // to do that could be skipped.
+        for action in self.action_queue.pop_ready(current_tick) {
+            if let EntityAction::SenescentDeath { id } = action {
+                if let Some(creature) = self.creatures.iter_mut().find(|c| c.id == id) {
+                    if creature.alive && !creature.senescent {
+                        creature.senescent = true;
+                        creature.telomeres = 0.0;
+                        events.push(Event::Senescent { id });
+                    }
+                }
+            }
+        }
+
         let mut dead_indices = Vec::new();
         let mut repro_ids = Vec::new(); // Use IDs to track reproduction parents safely
 
         // Pass 1: Analysis & Action
-        for (i, creature) in self.creatures.iter_mut().enumerate() {
-            if !creature.alive {
-                dead_indices.push(i);
-                continue;
-            }
+        // Gain/cost/movement is independent per creature, so this pass runs
+        // across creatures in parallel with rayon. Determinism is preserved
+        // by giving each creature its own reproducible RNG substream
+        // (seeded from the world seed, tick count, and creature id) instead
+        // of pulling from the single shared `self.rng` — so single-threaded
+        // and multi-threaded runs with the same seed produce identical
+        // outcomes regardless of scheduling order. Deaths and reproduction
+        // eligibility are collected here and applied serially, in
+        // deterministic ID order, in Passes 2-3 below.
+        let world_seed = self.seed;
+        let world_size = self.size;
+        let repro_threshold = self.repro_threshold;
+        let senescence_bmr_factor = self.senescence_bmr_factor;
+        let senescent_upkeep_multiplier = self.senescent_upkeep_multiplier;
+        let landscape = self.landscape;
+        let active_pulses = self.active_pulses.clone();
 
-            // 1. Gain (Use static helper to avoid borrowing &self)
-            let gain = Self::calculate_energy(current_tick, creature.pos);
-            
-            // 2. Cost
-            let loss = creature.phenotype.bmr;
-            
-            // 3. Apply
-            creature.energy += gain - loss;
-
-            // 4. Action: Attempt Random Move
-            let r1 = self.rng.next_u64();
-            let r2 = self.rng.next_u64();
-            let dx = (r1 % 3) as i32 - 1; 
-            let dy = (r2 % 3) as i32 - 1;
-            
-            if dx != 0 || dy != 0 {
-                let tx = (creature.pos.x as i32 + dx).max(0).min(self.size as i32 - 1) as u16;
-                let ty = (creature.pos.y as i32 + dy).max(0).min(self.size as i32 - 1) as u16;
-                
-                if let Some(_evt) = creature.move_to(Position { x: tx, y: ty }, self.size) {
-                    // events.push(evt); // Not strictly required by prompt output, but good for debug
+        // Each outcome is (index, death_reason, repro_eligible). The
+        // senescence transition itself is no longer detected here: it's
+        // scheduled ahead of time and consumed from the action queue in
+        // Pass 0.5, so `creature.senescent` is already current by the time
+        // this pass runs.
+        let outcomes: Vec<(usize, Option<&'static str>, bool)> = self
+            .creatures
+            .par_iter_mut()
+            .enumerate()
+            .map(|(i, creature)| {
+                if !creature.alive {
+                    return (i, Some("Energy Depletion"), false);
                 }
-            }
 
-            // 5. Check Vitals
-            if creature.energy <= 0.0 {
-                dead_indices.push(i);
-                continue; 
-            }
+                let mut local_rng = Rng::new(Self::splitmix64_seed(world_seed, current_tick, creature.id));
+
+                // 1. Gain (Use static helper to avoid borrowing &self). Active
+                // resource pulses stack on top of the base landscape value.
+                let mut gain = Self::calculate_energy(current_tick, creature.pos, &landscape);
+                for pulse in &active_pulses {
+                    if creature.pos.dist(&pulse.center) <= pulse.radius {
+                        gain += pulse.magnitude;
+                    }
+                }
+
+                // 2. Aging: telomeres decay every tick so `vitals()` reports
+                // progressive aging, not just the senescence step. The
+                // scheduled `SenescentDeath` transition (Pass 0.5) is still
+                // the authority on when `senescent` actually flips — this
+                // decrement is for reporting only and is clamped at zero.
+                creature.telomeres = (creature.telomeres - (1.0 + creature.phenotype.bmr * senescence_bmr_factor)).max(0.0);
+
+                // 3. Cost (senescent creatures pay increased upkeep)
+                let mut loss = creature.phenotype.bmr;
+                if creature.senescent {
+                    loss *= senescent_upkeep_multiplier;
+                }
+
+                // 4. Apply
+                creature.energy += gain - loss;
+                creature.age += 1;
+
+                // 5. Action: Attempt Random Move
+                let r1 = local_rng.next_u64();
+                let r2 = local_rng.next_u64();
+                let dx = (r1 % 3) as i32 - 1;
+                let dy = (r2 % 3) as i32 - 1;
+
+                if dx != 0 || dy != 0 {
+                    let tx = (creature.pos.x as i32 + dx).max(0).min(world_size as i32 - 1) as u16;
+                    let ty = (creature.pos.y as i32 + dy).max(0).min(world_size as i32 - 1) as u16;
+
+                    if let Some(_evt) = creature.move_to(Position { x: tx, y: ty }, world_size) {
+                        // events.push(evt); // Not strictly required by prompt output, but good for debug
+                    }
+                }
+
+                // 6. Check Vitals
+                if creature.energy <= 0.0 {
+                    let reason = if creature.senescent { "Senescence" } else { "Energy Depletion" };
+                    return (i, Some(reason), false);
+                }
 
-            // 6. Check Reproduction
-            let threshold = 50.0; // Adjusted threshold
-            // Ensure creature has enough energy to split (e.g. at least > cost)
-            if creature.energy > threshold {
-                 repro_ids.push(creature.id);
+                // 7. Check Reproduction (senescent creatures can no longer reproduce)
+                let repro_eligible = !creature.senescent && creature.energy > repro_threshold;
+                (i, None, repro_eligible)
+            })
+            .collect();
+
+        for (i, death_reason, repro_eligible) in outcomes {
+            if let Some(reason) = death_reason {
+                dead_indices.push((i, reason));
+            } else if repro_eligible {
+                repro_ids.push(self.creatures[i].id);
             }
         }
 
         // Pass 2: Process Deaths (Cleanup)
-        dead_indices.sort_by(|a, b| b.cmp(a)); // Descending
+        dead_indices.sort_by_key(|d| std::cmp::Reverse(d.0)); // Descending
         dead_indices.dedup();
 
-        for index in dead_indices {
+        for (index, reason) in dead_indices {
             if index < self.creatures.len() {
                 let id = self.creatures[index].id;
                 self.creatures.remove(index);
-                events.push(Event::Death { id, reason: "Energy Depletion".to_string() });
+                self.action_queue.cancel(id);
+                events.push(Event::Death { id, reason: reason.to_string() });
+            }
+        }
+
+        // Pass 3: Process Births (Selection)
+        // The mutation rate for this generation's offspring is derived
+        // from current population diversity/stagnation rather than a
+        // fixed constant.
+        let best_fitness_this_tick = self
+            .creatures
+            .iter()
+            .map(|c| c.fitness())
+            .fold(f32::MIN, f32::max)
+            .max(0.0);
+        let mutation_rate = self.mutation.rate(&self.creatures, best_fitness_this_tick, &mut self.rng);
+
+        // Rather than every energy-qualifying creature reproducing
+        // unconditionally, the selection strategy picks `repro_ids.len()`
+        // parents from the living, non-senescent creatures with more energy
+        // than `repro_cost`. This is where selective optimization of the
+        // population happens.
+        let living_refs: Vec<&Simulacrum> = self
+            .creatures
+            .iter()
+            .filter(|c| c.alive && !c.senescent && c.energy > self.repro_cost)
+            .collect();
+
+        let mut parent_ids = Vec::with_capacity(repro_ids.len());
+        for _ in 0..repro_ids.len() {
+            if let Some(parent) = self.selection.select(&living_refs, &mut self.rng) {
+                parent_ids.push(parent.id);
             }
         }
 
-        // Pass 3: Process Births (Growth)
-        // Note: Using IDs is O(N^2) here but safe and N is small (100).
         let mut offspring = Vec::new();
-        
-        for p_id in repro_ids {
-            // Find parent index
-            // We use iter_mut because we need to modify parent energy AND read genome
-            if let Some(parent) = self.creatures.iter_mut().find(|c| c.id == p_id) {
-                 // Double check parent is still valid/alive/has energy?
-                 // They should be, unless they died? No, specific logic excludes dead from repro_ids.
-                 // But wait, if died in move? No, checked after move and before repro add.
-                 
-                 let split_cost = 25.0; // Cost to birth
-                 if parent.energy > split_cost {
-                     parent.energy -= split_cost;
-                     
-                     let mut child_genome = parent.genome.clone();
-                     child_genome.mutate(&mut self.rng);
-                     
-                     let child_id = self.next_id;
-                     self.next_id += 1;
-                     
-                     let child = Simulacrum {
-                         id: child_id,
-                         genome: child_genome,
-                         phenotype: child_genome.decode(),
-                         pos: parent.pos, // Start at parent location
-                         energy: split_cost,
-                         alive: true,
-                     };
-                     
-                     offspring.push(child);
-                     events.push(Event::Birth { parent_id: parent.id });
-                 }
+        let mut used_as_partner: std::collections::HashSet<u64> = std::collections::HashSet::new();
+        let split_cost = self.repro_cost / 2.0;
+
+        for a_id in parent_ids {
+            // Snapshot the chosen parent's mating-relevant state before we
+            // start mutating anyone's energy.
+            let snapshot = self
+                .creatures
+                .iter()
+                .find(|c| c.id == a_id)
+                .map(|c| (c.pos, c.genome, c.phenotype.perception_radius));
+            let (a_pos, a_genome, a_perception) = match snapshot {
+                Some(s) => s,
+                None => continue,
+            };
+
+            // Rendezvous: pair with the nearest eligible, unpaired partner
+            // within perception range.
+            let partner = self
+                .creatures
+                .iter()
+                .filter(|c| {
+                    c.alive
+                        && !c.senescent
+                        && c.id != a_id
+                        && c.energy > split_cost
+                        && !used_as_partner.contains(&c.id)
+                        && c.pos.dist(&a_pos) <= a_perception
+                })
+                .min_by(|x, y| {
+                    x.pos
+                        .dist(&a_pos)
+                        .partial_cmp(&y.pos.dist(&a_pos))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|c| (c.id, c.pos, c.genome));
+
+            if let Some((b_id, b_pos, b_genome)) = partner {
+                let a_ok = self.creatures.iter().find(|c| c.id == a_id).is_some_and(|c| c.energy > split_cost);
+                if !a_ok {
+                    continue;
+                }
+
+                used_as_partner.insert(b_id);
+                if let Some(pa) = self.creatures.iter_mut().find(|c| c.id == a_id) {
+                    pa.energy -= split_cost;
+                }
+                if let Some(pb) = self.creatures.iter_mut().find(|c| c.id == b_id) {
+                    pb.energy -= split_cost;
+                }
+
+                let mut child_genome = a_genome.crossover(&b_genome, &mut self.rng);
+                child_genome.mutate(&mut self.rng, mutation_rate);
+
+                let child_id = self.next_id;
+                self.next_id += 1;
+
+                let child_pos = Position {
+                    x: ((a_pos.x as u32 + b_pos.x as u32) / 2) as u16,
+                    y: ((a_pos.y as u32 + b_pos.y as u32) / 2) as u16,
+                };
+                let mut child = Simulacrum::new(child_id, child_genome, child_pos);
+                child.energy = self.repro_cost;
+
+                self.schedule_senescence(&child);
+                offspring.push(child);
+                events.push(Event::Birth { parent_a: a_id, parent_b: Some(b_id) });
+            } else {
+                // No partner in perception range: fall back to the
+                // original asexual clone + mutate path.
+                let parent_snapshot = self
+                    .creatures
+                    .iter()
+                    .find(|c| c.id == a_id)
+                    .filter(|c| c.energy > self.repro_cost)
+                    .map(|c| (c.id, c.pos, c.genome));
+
+                if let Some((parent_id, parent_pos, parent_genome)) = parent_snapshot {
+                    if let Some(parent) = self.creatures.iter_mut().find(|c| c.id == a_id) {
+                        parent.energy -= self.repro_cost;
+                    }
+
+                    let mut child_genome = parent_genome;
+                    child_genome.mutate(&mut self.rng, mutation_rate);
+
+                    let child_id = self.next_id;
+                    self.next_id += 1;
+
+                    let mut child = Simulacrum::new(child_id, child_genome, parent_pos);
+                    child.energy = self.repro_cost;
+
+                    self.schedule_senescence(&child);
+                    offspring.push(child);
+                    events.push(Event::Birth { parent_a: parent_id, parent_b: None });
+                }
             }
         }
-        
+
         self.creatures.append(&mut offspring);
 
+        // Pass 4: Survival Pressure (cull back down to carrying capacity)
+        let culled = self.survival.apply(&mut self.creatures);
+        for event in &culled {
+            if let Event::Death { id, .. } = event {
+                self.action_queue.cancel(*id);
+            }
+        }
+        events.extend(culled);
+
         events
     }
 }
+
+// --- Part 5: Telemetry & Stop Criteria ---
+
+/// A fixed-bucket histogram over a known value range.
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    pub min: f32,
+    pub max: f32,
+    pub buckets: [u32; 32],
+}
+
+impl Histogram {
+    pub fn build(values: &[f32], min: f32, max: f32) -> Self {
+        let mut buckets = [0u32; 32];
+        let span = (max - min).max(f32::EPSILON);
+        for &v in values {
+            let clamped = v.clamp(min, max);
+            let idx = (((clamped - min) / span) * buckets.len() as f32) as usize;
+            buckets[idx.min(buckets.len() - 1)] += 1;
+        }
+        Histogram { min, max, buckets }
+    }
+}
+
+/// One generation's worth of population telemetry, suitable for streaming
+/// as JSONL alongside the existing event log.
+#[derive(Debug, Clone)]
+pub struct GenerationRecord {
+    pub tick: u64,
+    pub population: usize,
+    pub births: u64,
+    pub deaths: u64,
+    pub best_fitness: f32,
+    pub mean: Phenotype,
+    pub stddev: Phenotype,
+    pub bmr_histogram: Histogram,
+    pub body_mass_histogram: Histogram,
+}
+
+impl GenerationRecord {
+    pub fn to_jsonl(&self) -> String {
+        format!(
+            r#"{{"type":"Generation","tick":{},"population":{},"births":{},"deaths":{},"best_fitness":{},"mean":{{"bmr":{},"body_mass":{},"perception_radius":{},"max_lifespan":{}}},"stddev":{{"bmr":{},"body_mass":{},"perception_radius":{},"max_lifespan":{}}},"bmr_histogram":{:?},"body_mass_histogram":{:?}}}"#,
+            self.tick,
+            self.population,
+            self.births,
+            self.deaths,
+            self.best_fitness,
+            self.mean.bmr, self.mean.body_mass, self.mean.perception_radius, self.mean.max_lifespan,
+            self.stddev.bmr, self.stddev.body_mass, self.stddev.perception_radius, self.stddev.max_lifespan,
+            self.bmr_histogram.buckets,
+            self.body_mass_histogram.buckets,
+        )
+    }
+}
+
+/// Observer layered over `World`: fed the result of each tick, it records
+/// per-generation telemetry without the engine itself needing to know
+/// anything about it.
+pub struct Stats {
+    pub records: Vec<GenerationRecord>,
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Stats { records: Vec::new() }
+    }
+
+    /// Records a `GenerationRecord` from the world's current population
+    /// and this tick's events. Call once per tick, after `World::tick`.
+    pub fn observe(&mut self, world: &World, events: &[Event]) {
+        let births = events.iter().filter(|e| matches!(e, Event::Birth { .. })).count() as u64;
+        let deaths = events.iter().filter(|e| matches!(e, Event::Death { .. })).count() as u64;
+
+        let mean = Self::mean_phenotype(&world.creatures);
+        let stddev = Self::stddev_phenotype(&world.creatures, &mean);
+        let best_fitness = world
+            .creatures
+            .iter()
+            .map(|c| c.fitness())
+            .fold(f32::MIN, f32::max)
+            .max(0.0);
+
+        let bmrs: Vec<f32> = world.creatures.iter().map(|c| c.phenotype.bmr).collect();
+        let masses: Vec<f32> = world.creatures.iter().map(|c| c.phenotype.body_mass).collect();
+
+        self.records.push(GenerationRecord {
+            tick: world.tick_count,
+            population: world.creatures.len(),
+            births,
+            deaths,
+            best_fitness,
+            mean,
+            stddev,
+            bmr_histogram: Histogram::build(&bmrs, 0.5, 2.0),
+            body_mass_histogram: Histogram::build(&masses, 1.0, 100.0),
+        });
+    }
+
+    fn mean_phenotype(creatures: &[Simulacrum]) -> Phenotype {
+        let n = creatures.len().max(1) as f32;
+        let mut sum = Phenotype { bmr: 0.0, body_mass: 0.0, perception_radius: 0.0, max_lifespan: 0.0 };
+        for c in creatures {
+            sum.bmr += c.phenotype.bmr;
+            sum.body_mass += c.phenotype.body_mass;
+            sum.perception_radius += c.phenotype.perception_radius;
+            sum.max_lifespan += c.phenotype.max_lifespan;
+        }
+        Phenotype {
+            bmr: sum.bmr / n,
+            body_mass: sum.body_mass / n,
+            perception_radius: sum.perception_radius / n,
+            max_lifespan: sum.max_lifespan / n,
+        }
+    }
+
+    fn stddev_phenotype(creatures: &[Simulacrum], mean: &Phenotype) -> Phenotype {
+        let n = creatures.len().max(1) as f32;
+        let mut sq = Phenotype { bmr: 0.0, body_mass: 0.0, perception_radius: 0.0, max_lifespan: 0.0 };
+        for c in creatures {
+            sq.bmr += (c.phenotype.bmr - mean.bmr).powi(2);
+            sq.body_mass += (c.phenotype.body_mass - mean.body_mass).powi(2);
+            sq.perception_radius += (c.phenotype.perception_radius - mean.perception_radius).powi(2);
+            sq.max_lifespan += (c.phenotype.max_lifespan - mean.max_lifespan).powi(2);
+        }
+        Phenotype {
+            bmr: (sq.bmr / n).sqrt(),
+            body_mass: (sq.body_mass / n).sqrt(),
+            perception_radius: (sq.perception_radius / n).sqrt(),
+            max_lifespan: (sq.max_lifespan / n).sqrt(),
+        }
+    }
+
+    /// Streams every recorded generation as JSONL, one record per line.
+    pub fn to_jsonl(&self) -> String {
+        self.records.iter().map(|r| r.to_jsonl()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Automatic stop criteria a driver loop can check after each tick instead
+/// of hard-coding a tick count.
+#[derive(Debug, Clone)]
+pub enum StopCriterion {
+    MaxTicks(u64),
+    Extinction,
+    /// Stops once the absolute slope of best-fitness over the trailing
+    /// `window` generations falls below `epsilon`.
+    FitnessPlateau { window: usize, epsilon: f32 },
+    TargetPopulation(usize),
+}
+
+impl StopCriterion {
+    /// Checked at the end of each tick; `stats` must already include this
+    /// tick's `GenerationRecord`.
+    pub fn is_met(&self, world: &World, stats: &Stats) -> bool {
+        match *self {
+            StopCriterion::MaxTicks(max) => world.tick_count >= max,
+            StopCriterion::Extinction => world.creatures.is_empty(),
+            StopCriterion::TargetPopulation(target) => world.creatures.len() >= target,
+            StopCriterion::FitnessPlateau { window, epsilon } => {
+                if stats.records.len() < window {
+                    return false;
+                }
+                let tail = &stats.records[stats.records.len() - window..];
+                let best_fitness: Vec<f32> = tail.iter().map(|r| r.best_fitness).collect();
+                Self::slope(&best_fitness).abs() < epsilon
+            }
+        }
+    }
+
+    /// Least-squares slope of `ys` against their index.
+    fn slope(ys: &[f32]) -> f32 {
+        let n = ys.len() as f32;
+        if n < 2.0 {
+            return 0.0;
+        }
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = ys.iter().sum::<f32>() / n;
+
+        let mut num = 0.0;
+        let mut den = 0.0;
+        for (i, &y) in ys.iter().enumerate() {
+            let x = i as f32;
+            num += (x - mean_x) * (y - mean_y);
+            den += (x - mean_x).powi(2);
+        }
+
+        if den.abs() < f32::EPSILON {
+            0.0
+        } else {
+            num / den
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Seeds a world with a small population and runs it for `ticks`,
+    /// returning the serialized event log in emission order.
+    fn run_world(seed: u64, ticks: u64) -> Vec<String> {
+        let mut world = World::new(50, seed);
+
+        let mut seed_rng = Rng::new(seed);
+        for _ in 0..20 {
+            let genome = Genome::from_seed(seed_rng.next_u64());
+            let pos = Position {
+                x: (seed_rng.next_u64() % 50) as u16,
+                y: (seed_rng.next_u64() % 50) as u16,
+            };
+            let id = world.next_id;
+            world.next_id += 1;
+            let creature = Simulacrum::new(id, genome, pos);
+            world.schedule_senescence(&creature);
+            world.creatures.push(creature);
+        }
+
+        let mut log = Vec::new();
+        for _ in 0..ticks {
+            for event in world.tick() {
+                log.push(event.to_jsonl());
+            }
+        }
+        log
+    }
+
+    #[test]
+    fn tick_is_deterministic_across_thread_pool_sizes() {
+        let single_threaded = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        let multi_threaded = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+
+        let single_threaded_log = single_threaded.install(|| run_world(12345, 50));
+        let multi_threaded_log = multi_threaded.install(|| run_world(12345, 50));
+
+        assert_eq!(single_threaded_log, multi_threaded_log);
+    }
+
+    #[test]
+    fn parse_scheduled_event_preserves_seeds_past_f32_precision() {
+        // f32 only has 24 bits of exact integer precision, so parsing an
+        // integer field through f32 silently rounds seeds above ~16.7M.
+        let scenario = Scenario::parse("100 InjectCreatures count=3 seed=123456789").unwrap();
+        let (tick, event) = &scenario.schedule[0];
+        assert_eq!(*tick, 100);
+        match event {
+            ScheduledEvent::InjectCreatures { count, seed } => {
+                assert_eq!(*count, 3);
+                assert_eq!(*seed, 123456789);
+            }
+            other => panic!("expected InjectCreatures, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn scenario_parse_rejects_a_malformed_parameter_line() {
+        let err = Scenario::parse("not_a_key_value_pair").unwrap_err();
+        assert!(err.contains("malformed scenario parameter"), "got: {}", err);
+    }
+
+    #[test]
+    fn scenario_parse_rejects_an_unknown_parameter() {
+        let err = Scenario::parse("not_a_real_param=1").unwrap_err();
+        assert!(err.contains("unknown scenario parameter"), "got: {}", err);
+    }
+
+    #[test]
+    fn scenario_parse_rejects_an_unknown_event_kind() {
+        let err = Scenario::parse("10 NotARealEvent foo=1").unwrap_err();
+        assert!(err.contains("unknown scheduled event kind") && err.contains("NotARealEvent"), "got: {}", err);
+    }
+
+    #[test]
+    fn scenario_parse_rejects_a_scheduled_event_missing_a_field() {
+        let err = Scenario::parse("10 InjectCreatures seed=1").unwrap_err();
+        assert!(err.contains("count"), "got: {}", err);
+    }
+
+    #[test]
+    fn scenario_parse_accepts_comments_and_blank_lines() {
+        let scenario = Scenario::parse("# a comment\n\nworld_size=64\nseed=7\n").unwrap();
+        assert_eq!(scenario.world_size, 64);
+        assert_eq!(scenario.seed, 7);
+    }
+
+    fn make_creature(id: u64, seed: u64, energy: f32) -> Simulacrum {
+        let mut creature = Simulacrum::new(id, Genome::from_seed(seed), Position { x: 0, y: 0 });
+        creature.energy = energy;
+        creature
+    }
+
+    #[test]
+    fn tournament_selection_prefers_the_fitter_candidate() {
+        // Tournament draws with replacement, but `Rng`'s low bit alternates
+        // between consecutive draws, so k == 2 over a two-creature pool
+        // always draws both indices and the fittest one wins.
+        let weak = make_creature(1, 1, 1.0);
+        let strong = make_creature(2, 2, 1000.0);
+        let pool = vec![&weak, &strong];
+        let strategy = SelectionStrategy::Tournament { k: 2 };
+        let mut rng = Rng::new(7);
+
+        let winner = strategy.select(&pool, &mut rng).unwrap();
+        assert_eq!(winner.id, strong.id);
+    }
+
+    #[test]
+    fn selection_strategies_return_none_for_empty_pool() {
+        let pool: Vec<&Simulacrum> = Vec::new();
+        let mut rng = Rng::new(1);
+        assert!(SelectionStrategy::Tournament { k: 3 }.select(&pool, &mut rng).is_none());
+        assert!(SelectionStrategy::RouletteWheel.select(&pool, &mut rng).is_none());
+        assert!(SelectionStrategy::RankBased.select(&pool, &mut rng).is_none());
+    }
+
+    #[test]
+    fn survival_pressure_culls_lowest_fitness_first_down_to_capacity() {
+        // fitness() = energy.max(0.0) * (age + 1), all ages 0 here, so
+        // fitness is ordered purely by energy.
+        let creatures = vec![
+            make_creature(1, 1, 10.0),
+            make_creature(2, 2, 50.0),
+            make_creature(3, 3, 5.0),
+        ];
+        let mut pop = creatures;
+        let survival = SurvivalPressure::new(2);
+
+        let events = survival.apply(&mut pop);
+
+        assert_eq!(pop.len(), 2);
+        assert_eq!(events.len(), 1);
+        // The lowest-energy creature (id 3) is the one culled.
+        assert!(pop.iter().all(|c| c.id != 3));
+        match &events[0] {
+            Event::Death { id, reason } => {
+                assert_eq!(*id, 3);
+                assert_eq!(reason, "Survival Pressure");
+            }
+            other => panic!("expected Death event, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn survival_pressure_is_a_no_op_under_capacity() {
+        let mut pop = vec![make_creature(1, 1, 10.0)];
+        let survival = SurvivalPressure::new(5);
+        let events = survival.apply(&mut pop);
+        assert!(events.is_empty());
+        assert_eq!(pop.len(), 1);
+    }
+
+    fn new_test_world() -> World {
+        let mut world = World::new(50, 99);
+        // Low enough that the high-energy test creatures below stay
+        // reproduction-eligible after paying a tick's upkeep.
+        world.repro_threshold = 10.0;
+        world.repro_cost = 10.0;
+        world
+    }
+
+    #[test]
+    fn reproduction_pairs_nearby_eligible_partners_by_rendezvous() {
+        let mut world = new_test_world();
+
+        let mut a = Simulacrum::new(1, Genome::from_seed(1), Position { x: 25, y: 25 });
+        a.energy = 1000.0;
+        a.phenotype.perception_radius = 50.0;
+        let mut b = Simulacrum::new(2, Genome::from_seed(2), Position { x: 26, y: 25 });
+        b.energy = 1000.0;
+        b.phenotype.perception_radius = 50.0;
+        world.next_id = 3;
+        world.creatures.push(a);
+        world.creatures.push(b);
+
+        let events = world.tick();
+
+        let births: Vec<&Event> = events.iter().filter(|e| matches!(e, Event::Birth { .. })).collect();
+        assert!(!births.is_empty(), "expected at least one Birth event, got {:?}", events);
+        assert!(
+            births.iter().any(|e| matches!(e, Event::Birth { parent_b: Some(_), .. })),
+            "expected a sexual Birth (parent_b: Some) from the two nearby partners, got {:?}",
+            births
+        );
+    }
+
+    #[test]
+    fn reproduction_falls_back_to_asexual_when_no_partner_in_range() {
+        let mut world = new_test_world();
+
+        let mut lone = Simulacrum::new(1, Genome::from_seed(1), Position { x: 0, y: 0 });
+        lone.energy = 1000.0;
+        lone.phenotype.perception_radius = 50.0;
+        world.next_id = 2;
+        world.creatures.push(lone);
+
+        let events = world.tick();
+
+        let births: Vec<&Event> = events.iter().filter(|e| matches!(e, Event::Birth { .. })).collect();
+        assert!(!births.is_empty(), "expected at least one Birth event, got {:?}", events);
+        assert!(
+            births.iter().all(|e| matches!(e, Event::Birth { parent_b: None, .. })),
+            "expected only asexual Births (parent_b: None) with a single creature, got {:?}",
+            births
+        );
+    }
+
+    #[test]
+    fn histogram_buckets_values_by_their_position_in_the_range() {
+        // Range [0, 32) split into 32 buckets is one unit wide each, so
+        // value v should land in bucket v (barring the top edge).
+        let values = vec![0.0, 1.0, 31.0];
+        let hist = Histogram::build(&values, 0.0, 32.0);
+        assert_eq!(hist.buckets[0], 1);
+        assert_eq!(hist.buckets[1], 1);
+        assert_eq!(hist.buckets[31], 1);
+        assert_eq!(hist.buckets.iter().sum::<u32>(), 3);
+    }
+
+    #[test]
+    fn histogram_clamps_out_of_range_values_into_the_edge_buckets() {
+        let values = vec![-100.0, 100.0];
+        let hist = Histogram::build(&values, 0.0, 32.0);
+        assert_eq!(hist.buckets[0], 1);
+        assert_eq!(hist.buckets[31], 1);
+        assert_eq!(hist.buckets.iter().sum::<u32>(), 2);
+    }
+
+    #[test]
+    fn generation_record_to_jsonl_round_trips_its_fields() {
+        let phenotype = Phenotype { bmr: 1.0, body_mass: 2.0, perception_radius: 3.0, max_lifespan: 4.0 };
+        let record = GenerationRecord {
+            tick: 7,
+            population: 5,
+            births: 2,
+            deaths: 1,
+            best_fitness: 42.5,
+            mean: phenotype,
+            stddev: phenotype,
+            bmr_histogram: Histogram::build(&[], 0.5, 2.0),
+            body_mass_histogram: Histogram::build(&[], 1.0, 100.0),
+        };
+
+        let json = record.to_jsonl();
+        assert!(json.contains(r#""tick":7"#));
+        assert!(json.contains(r#""population":5"#));
+        assert!(json.contains(r#""births":2"#));
+        assert!(json.contains(r#""deaths":1"#));
+        assert!(json.contains(r#""best_fitness":42.5"#));
+    }
+
+    #[test]
+    fn stop_criterion_max_ticks_and_extinction() {
+        let world = World::new(10, 1);
+        let stats = Stats::new();
+        assert!(!StopCriterion::MaxTicks(5).is_met(&world, &stats));
+
+        let mut world_at_5 = World::new(10, 1);
+        world_at_5.tick_count = 5;
+        assert!(StopCriterion::MaxTicks(5).is_met(&world_at_5, &stats));
+
+        assert!(StopCriterion::Extinction.is_met(&world, &stats));
+
+        let mut world_with_creature = World::new(10, 1);
+        world_with_creature.creatures.push(make_creature(1, 1, 1.0));
+        assert!(!StopCriterion::Extinction.is_met(&world_with_creature, &stats));
+        assert!(StopCriterion::TargetPopulation(1).is_met(&world_with_creature, &stats));
+        assert!(!StopCriterion::TargetPopulation(2).is_met(&world_with_creature, &stats));
+    }
+
+    #[test]
+    fn stop_criterion_fitness_plateau_uses_least_squares_slope() {
+        let world = World::new(10, 1);
+        let mut stats = Stats::new();
+
+        // A strictly increasing trend should NOT read as a plateau.
+        for (tick, best_fitness) in [1.0, 2.0, 3.0, 4.0].into_iter().enumerate() {
+            stats.records.push(GenerationRecord {
+                tick: tick as u64,
+                population: 1,
+                births: 0,
+                deaths: 0,
+                best_fitness,
+                mean: Phenotype { bmr: 0.0, body_mass: 0.0, perception_radius: 0.0, max_lifespan: 0.0 },
+                stddev: Phenotype { bmr: 0.0, body_mass: 0.0, perception_radius: 0.0, max_lifespan: 0.0 },
+                bmr_histogram: Histogram::build(&[], 0.5, 2.0),
+                body_mass_histogram: Histogram::build(&[], 1.0, 100.0),
+            });
+        }
+        let trending = StopCriterion::FitnessPlateau { window: 4, epsilon: 0.01 };
+        assert!(!trending.is_met(&world, &stats));
+
+        // A flat trend (identical best_fitness every generation) has slope
+        // 0 and should read as a plateau.
+        let mut flat_stats = Stats::new();
+        for tick in 0..4u64 {
+            flat_stats.records.push(GenerationRecord {
+                tick,
+                population: 1,
+                births: 0,
+                deaths: 0,
+                best_fitness: 10.0,
+                mean: Phenotype { bmr: 0.0, body_mass: 0.0, perception_radius: 0.0, max_lifespan: 0.0 },
+                stddev: Phenotype { bmr: 0.0, body_mass: 0.0, perception_radius: 0.0, max_lifespan: 0.0 },
+                bmr_histogram: Histogram::build(&[], 0.5, 2.0),
+                body_mass_histogram: Histogram::build(&[], 1.0, 100.0),
+            });
+        }
+        let plateaued = StopCriterion::FitnessPlateau { window: 4, epsilon: 0.01 };
+        assert!(plateaued.is_met(&world, &flat_stats));
+
+        // Fewer generations than `window` can never trigger the plateau.
+        let mut short_stats = Stats::new();
+        short_stats.records.push(flat_stats.records[0].clone());
+        let too_short = StopCriterion::FitnessPlateau { window: 4, epsilon: 0.01 };
+        assert!(!too_short.is_met(&world, &short_stats));
+    }
+
+    #[test]
+    fn adaptive_mutation_rate_rises_as_diversity_falls() {
+        let mut rng = Rng::new(1);
+
+        // Zero diversity (identical genomes): rate = base * (1 + gain).
+        let identical = vec![
+            Simulacrum::new(1, Genome { bytes: [0u8; 64] }, Position { x: 0, y: 0 }),
+            Simulacrum::new(2, Genome { bytes: [0u8; 64] }, Position { x: 0, y: 0 }),
+        ];
+        let mut low_diversity = AdaptiveMutation::new(0.1);
+        let rate_low_diversity = low_diversity.rate(&identical, 0.0, &mut rng);
+        assert!((rate_low_diversity - 0.3).abs() < 1e-6);
+
+        // Maximal diversity (bitwise-complementary genomes): rate = base.
+        let maximally_diverse = vec![
+            Simulacrum::new(1, Genome { bytes: [0u8; 64] }, Position { x: 0, y: 0 }),
+            Simulacrum::new(2, Genome { bytes: [0xFFu8; 64] }, Position { x: 0, y: 0 }),
+        ];
+        let mut high_diversity = AdaptiveMutation::new(0.1);
+        let rate_high_diversity = high_diversity.rate(&maximally_diverse, 0.0, &mut rng);
+        assert!((rate_high_diversity - 0.1).abs() < 1e-6);
+
+        assert!(rate_low_diversity > rate_high_diversity);
+    }
+
+    #[test]
+    fn adaptive_mutation_stagnant_generations_counts_trailing_non_improvements() {
+        // The running best resets the streak, so only entries after the
+        // most recent new best count as stagnant.
+        let mut mutation = AdaptiveMutation::new(0.1);
+        mutation.best_fitness_history = vec![10.0, 1.0, 1.0, 1.0];
+        assert_eq!(mutation.stagnant_generations(), 3);
+
+        mutation.best_fitness_history = vec![1.0, 2.0, 3.0];
+        assert_eq!(mutation.stagnant_generations(), 0);
+
+        mutation.best_fitness_history = vec![];
+        assert_eq!(mutation.stagnant_generations(), 0);
+    }
+
+    #[test]
+    fn adaptive_mutation_rate_applies_stagnation_multiplier() {
+        let creatures = vec![
+            Simulacrum::new(1, Genome { bytes: [0u8; 64] }, Position { x: 0, y: 0 }),
+            Simulacrum::new(2, Genome { bytes: [0xFFu8; 64] }, Position { x: 0, y: 0 }),
+        ];
+        let mut rng = Rng::new(1);
+
+        // Force the pre-existing history past the stagnation window so
+        // `rate`'s multiplier branch is exercised directly, rather than
+        // relying on enough successive `rate()` calls to build it up.
+        let mut mutation = AdaptiveMutation::new(0.1);
+        mutation.stagnation_window = 2;
+        mutation.best_fitness_history = vec![10.0, 1.0, 1.0];
+        let stagnant_rate = mutation.rate(&creatures, 1.0, &mut rng);
+
+        let mut fresh = AdaptiveMutation::new(0.1);
+        fresh.stagnation_window = 2;
+        let baseline_rate = fresh.rate(&creatures, 1.0, &mut rng);
+
+        assert!(stagnant_rate > baseline_rate);
+    }
+
+    #[test]
+    fn schedule_senescence_predicts_the_exact_tick_telomeres_hit_zero() {
+        let mut world = World::new(10, 1);
+        world.senescence_bmr_factor = 1.0;
+
+        let mut creature = Simulacrum::new(1, Genome::from_seed(1), Position { x: 0, y: 0 });
+        creature.phenotype.bmr = 1.0;
+        creature.telomeres = 10.0;
+        // decay = 1.0 + bmr * senescence_bmr_factor = 2.0/tick, so
+        // ceil(10.0 / 2.0) = 5 ticks until telomeres reaches zero.
+        world.schedule_senescence(&creature);
+
+        assert!(world.action_queue.pop_ready(4).is_empty());
+        let due = world.action_queue.pop_ready(5);
+        assert_eq!(due, vec![EntityAction::SenescentDeath { id: 1 }]);
+    }
+
+    #[test]
+    fn event_queue_pop_ready_drops_cancelled_entries() {
+        let mut queue = EventQueue::new();
+        queue.push(5, EntityAction::SenescentDeath { id: 1 });
+        queue.push(5, EntityAction::SenescentDeath { id: 2 });
+        queue.cancel(1);
+
+        let ready = queue.pop_ready(5);
+
+        assert_eq!(ready, vec![EntityAction::SenescentDeath { id: 2 }]);
+    }
+
+    #[test]
+    fn event_queue_pop_ready_only_returns_entries_due_by_now() {
+        let mut queue = EventQueue::new();
+        queue.push(10, EntityAction::SenescentDeath { id: 1 });
+
+        assert!(queue.pop_ready(9).is_empty());
+        assert_eq!(queue.pop_ready(10), vec![EntityAction::SenescentDeath { id: 1 }]);
+        // Already popped: a second call at the same tick finds nothing left.
+        assert!(queue.pop_ready(10).is_empty());
+    }
+
+    #[test]
+    fn event_queue_cancel_after_push_does_not_affect_entries_scheduled_later() {
+        // `cancel` bumps the entity's generation; an entry pushed after the
+        // cancel (with the new generation) should still fire normally.
+        let mut queue = EventQueue::new();
+        queue.push(5, EntityAction::SenescentDeath { id: 1 });
+        queue.cancel(1);
+        queue.push(10, EntityAction::SenescentDeath { id: 1 });
+
+        assert!(queue.pop_ready(5).is_empty());
+        assert_eq!(queue.pop_ready(10), vec![EntityAction::SenescentDeath { id: 1 }]);
+    }
+
+    #[test]
+    fn schedule_senescence_schedules_at_least_one_tick_ahead() {
+        let mut world = World::new(10, 1);
+        world.senescence_bmr_factor = 1.0;
+
+        // telomeres already at zero would compute ceil(0.0 / decay) = 0
+        // ticks, but firing in the same tick it's scheduled isn't
+        // meaningful, so this is clamped up to 1.
+        let mut creature = Simulacrum::new(1, Genome::from_seed(1), Position { x: 0, y: 0 });
+        creature.phenotype.bmr = 1.0;
+        creature.telomeres = 0.0;
+        world.tick_count = 7;
+        world.schedule_senescence(&creature);
+
+        assert!(world.action_queue.pop_ready(7).is_empty());
+        assert_eq!(world.action_queue.pop_ready(8), vec![EntityAction::SenescentDeath { id: 1 }]);
+    }
+}